@@ -0,0 +1,132 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0.
+ */
+
+//! Default region provider chain
+
+use crate::environment::region::EnvironmentVariableRegionProvider;
+use crate::imds::region::ImdsRegionProvider;
+use crate::meta::region::{future, ProvideRegion};
+use crate::profile::ProfileFileRegionProvider;
+use crate::provider_config::ProviderConfig;
+use aws_types::region::Region;
+
+/// Default region provider chain
+///
+/// This provider will check the following sources in order:
+/// 1. [Environment variables](EnvironmentVariableRegionProvider): `AWS_REGION` then `AWS_DEFAULT_REGION`
+/// 2. [Profile file](ProfileFileRegionProvider): `region` from the active profile
+/// 3. [EC2 IMDS](ImdsRegionProvider): the region of the currently running EC2 instance
+///
+/// This provider will always check `AWS_REGION` and `AWS_DEFAULT_REGION` before checking
+/// the profile and IMDS, since reading environment variables is assumed to be cheap and
+/// IMDS lookups require a network call.
+#[derive(Debug)]
+pub struct DefaultRegionChain {
+    env_provider: EnvironmentVariableRegionProvider,
+    profile_file: ProfileFileRegionProvider,
+    imds: ImdsRegionProvider,
+}
+
+impl DefaultRegionChain {
+    /// Builder for [`DefaultRegionChain`]
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    async fn region(&self) -> Option<Region> {
+        if let Some(region) = self.env_provider.region().await {
+            return Some(region);
+        }
+        if let Some(region) = self.profile_file.region().await {
+            return Some(region);
+        }
+        self.imds.region().await
+    }
+}
+
+impl ProvideRegion for DefaultRegionChain {
+    fn region(&self) -> future::ProvideRegion {
+        future::ProvideRegion::new(DefaultRegionChain::region(self))
+    }
+}
+
+/// Builder for [`DefaultRegionChain`]
+#[derive(Default, Debug)]
+pub struct Builder {
+    conf: Option<ProviderConfig>,
+    profile_override: Option<String>,
+}
+
+impl Builder {
+    /// Share a single [`ProviderConfig`] (fs, env, and HTTP connector) across the env, profile,
+    /// and IMDS sub-providers
+    pub fn configure(mut self, conf: &ProviderConfig) -> Self {
+        self.conf = Some(conf.clone());
+        self
+    }
+
+    /// Override the profile name used by the [profile file](ProfileFileRegionProvider) sub-provider
+    pub fn profile_name(mut self, name: impl Into<String>) -> Self {
+        self.profile_override = Some(name.into());
+        self
+    }
+
+    /// Build a [`DefaultRegionChain`]
+    pub fn build(self) -> DefaultRegionChain {
+        let conf = self.conf.unwrap_or_default();
+        let mut profile_file = ProfileFileRegionProvider::builder().configure(&conf);
+        if let Some(profile) = self.profile_override {
+            profile_file = profile_file.profile_name(profile);
+        }
+        DefaultRegionChain {
+            env_provider: EnvironmentVariableRegionProvider::new_with_env(conf.env()),
+            profile_file: profile_file.build(),
+            imds: ImdsRegionProvider::builder().configure(&conf).build(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DefaultRegionChain;
+    use crate::provider_config::ProviderConfig;
+    use crate::test_case::no_traffic_connector;
+    use aws_types::os_shim_internal::{Env, Fs};
+    use aws_types::region::Region;
+
+    #[tokio::test]
+    async fn env_wins_over_profile_and_imds() {
+        // A connector that errors on any traffic: if this test passes, the env provider short-
+        // circuited before the chain ever reached IMDS.
+        let fs = Fs::from_slice(&[("test_config", "[default]\nregion = us-west-2\n")]);
+        let env = Env::from_slice(&[
+            ("AWS_REGION", "us-east-1"),
+            ("AWS_CONFIG_FILE", "test_config"),
+        ]);
+        let conf = ProviderConfig::empty()
+            .with_fs(fs)
+            .with_env(env)
+            .with_http_connector(no_traffic_connector());
+
+        let chain = DefaultRegionChain::builder().configure(&conf).build();
+        assert_eq!(Some(Region::new("us-east-1")), chain.region().await);
+    }
+
+    #[tokio::test]
+    async fn profile_wins_over_imds() {
+        // No AWS_REGION/AWS_DEFAULT_REGION, so the env provider yields nothing and the chain
+        // must fall through to the profile. The no-traffic connector means this only passes if
+        // the profile's region short-circuits the chain before it reaches IMDS.
+        let fs = Fs::from_slice(&[("test_config", "[default]\nregion = us-west-2\n")]);
+        let env = Env::from_slice(&[("AWS_CONFIG_FILE", "test_config")]);
+        let conf = ProviderConfig::empty()
+            .with_fs(fs)
+            .with_env(env)
+            .with_http_connector(no_traffic_connector());
+
+        let chain = DefaultRegionChain::builder().configure(&conf).build();
+        assert_eq!(Some(Region::new("us-west-2")), chain.region().await);
+    }
+}
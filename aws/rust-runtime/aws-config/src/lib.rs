@@ -0,0 +1,9 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0.
+ */
+
+//! Providers for retrieving region, credentials, and other configuration from the environment
+
+pub mod default_provider;
+pub mod profile;
@@ -0,0 +1,14 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0.
+ */
+
+//! Providers that load configuration from the shared AWS config and credentials files
+
+pub mod profile_file;
+
+pub(crate) mod parser;
+pub(crate) use parser::ProfileSet;
+
+pub mod region;
+pub use region::ProfileFileRegionProvider;
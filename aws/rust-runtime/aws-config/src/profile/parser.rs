@@ -0,0 +1,201 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0.
+ */
+
+//! Parses the AWS shared config and credentials files into a [`ProfileSet`]
+
+use super::profile_file::{ProfileFileKind, ProfileFiles};
+use aws_types::os_shim_internal::{Env, Fs};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+
+const DEFAULT_PROFILE: &str = "default";
+const PROFILE_ENV_VAR: &str = "AWS_PROFILE";
+const CONFIG_FILE_ENV_VAR: &str = "AWS_CONFIG_FILE";
+const CREDENTIALS_FILE_ENV_VAR: &str = "AWS_SHARED_CREDENTIALS_FILE";
+const DEFAULT_CONFIG_PATH: &str = ".aws/config";
+const DEFAULT_CREDENTIALS_PATH: &str = ".aws/credentials";
+
+/// A single parsed `[profile]` or `[default]` section
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Profile {
+    properties: HashMap<String, String>,
+}
+
+impl Profile {
+    /// Retrieve a property from this profile, if present
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        self.properties.get(name).map(String::as_str)
+    }
+
+    fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.properties.insert(key.into(), value.into());
+    }
+}
+
+/// The merged set of profiles loaded from the shared config and credentials files
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ProfileSet {
+    profiles: HashMap<String, Profile>,
+    selected_profile: String,
+}
+
+impl ProfileSet {
+    /// True if no profiles were loaded
+    pub(crate) fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+
+    /// The name of the profile that should be used absent an explicit override, i.e. the
+    /// `AWS_PROFILE` environment variable, or `"default"` if it isn't set
+    pub(crate) fn selected_profile(&self) -> &str {
+        &self.selected_profile
+    }
+
+    /// Retrieve a profile by name
+    pub(crate) fn get_profile(&self, name: &str) -> Option<&Profile> {
+        self.profiles.get(name)
+    }
+
+    /// Merge `other`'s profiles into `self`, with `other`'s properties taking precedence when
+    /// both define the same key in the same profile
+    fn merge_in(&mut self, other: ProfileSet) {
+        for (name, profile) in other.profiles {
+            let merged = self.profiles.entry(name).or_default();
+            for (key, value) in profile.properties {
+                merged.set(key, value);
+            }
+        }
+    }
+}
+
+/// Error encountered while loading or parsing the shared config/credentials files
+#[derive(Debug)]
+pub(crate) struct ProfileParseError {
+    message: String,
+}
+
+impl ProfileParseError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ProfileParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to load profile: {}", self.message)
+    }
+}
+
+impl Error for ProfileParseError {}
+
+/// Load the [`ProfileSet`] described by `profile_files`
+///
+/// For each [`ProfileFileKind`] in `profile_files` that was given an explicit path (see
+/// [`crate::profile::profile_file::Builder::with_file`]), that file is loaded and it is an error
+/// if it cannot be read. For files at their default location, only a *missing* file (i.e. the
+/// file doesn't exist) is treated as empty rather than an error — any other I/O error (permission
+/// denied, the path being a directory, etc.) still fails the load. Files are merged in the order
+/// they appear in `profile_files`, with later files taking precedence when they define the same
+/// key in the same profile.
+pub(crate) async fn load(
+    fs: &Fs,
+    env: &Env,
+    profile_files: &ProfileFiles,
+) -> Result<ProfileSet, ProfileParseError> {
+    let mut merged = ProfileSet {
+        selected_profile: env
+            .get(PROFILE_ENV_VAR)
+            .unwrap_or_else(|_| DEFAULT_PROFILE.to_string()),
+        ..Default::default()
+    };
+
+    for (kind, explicit_path) in profile_files.files() {
+        let path = match explicit_path {
+            Some(path) => path.clone(),
+            None => match default_location(env, kind) {
+                Some(path) => path,
+                // We couldn't determine a default location (e.g. no home directory); treat this
+                // file as absent rather than failing the whole load.
+                None => continue,
+            },
+        };
+
+        let contents = match fs.read_to_end(&path).await {
+            Ok(contents) => contents,
+            Err(err)
+                if explicit_path.is_none()
+                    && err.kind() == std::io::ErrorKind::NotFound =>
+            {
+                continue
+            }
+            Err(err) => {
+                return Err(ProfileParseError::new(format!(
+                    "failed to read {:?}: {}",
+                    path, err
+                )))
+            }
+        };
+        let contents = String::from_utf8(contents).map_err(|err| {
+            ProfileParseError::new(format!("{:?} was not valid UTF-8: {}", path, err))
+        })?;
+
+        merged.merge_in(parse_ini(&contents, kind));
+    }
+
+    Ok(merged)
+}
+
+fn default_location(env: &Env, kind: &ProfileFileKind) -> Option<PathBuf> {
+    let (env_var, default_suffix) = match kind {
+        ProfileFileKind::Config => (CONFIG_FILE_ENV_VAR, DEFAULT_CONFIG_PATH),
+        ProfileFileKind::Credentials => (CREDENTIALS_FILE_ENV_VAR, DEFAULT_CREDENTIALS_PATH),
+    };
+    if let Ok(overridden) = env.get(env_var) {
+        return Some(PathBuf::from(overridden));
+    }
+    let home = env.get("HOME").or_else(|_| env.get("USERPROFILE")).ok()?;
+    Some(PathBuf::from(home).join(default_suffix))
+}
+
+/// Parse the contents of a single config/credentials file into a [`ProfileSet`]
+///
+/// Section headers look like `[default]` or `[profile name]` in the config file, and just
+/// `[name]` in the credentials file. Everything else is a `key = value` property of the most
+/// recently seen section; blank lines and lines starting with `#`/`;` are ignored.
+fn parse_ini(contents: &str, kind: &ProfileFileKind) -> ProfileSet {
+    let mut profiles: HashMap<String, Profile> = HashMap::new();
+    let mut current: Option<String> = None;
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let name = match kind {
+                ProfileFileKind::Credentials => header.trim(),
+                ProfileFileKind::Config => header.strip_prefix("profile ").unwrap_or(header).trim(),
+            };
+            profiles.entry(name.to_string()).or_default();
+            current = Some(name.to_string());
+            continue;
+        }
+        if let (Some(name), Some((key, value))) = (&current, line.split_once('=')) {
+            profiles
+                .entry(name.clone())
+                .or_default()
+                .set(key.trim(), value.trim());
+        }
+    }
+
+    ProfileSet {
+        profiles,
+        selected_profile: DEFAULT_PROFILE.to_string(),
+    }
+}
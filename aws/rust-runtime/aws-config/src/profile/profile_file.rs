@@ -0,0 +1,127 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0.
+ */
+
+//! Locations for the shared AWS config and credentials files
+
+use std::path::PathBuf;
+
+/// The kind of profile file
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ProfileFileKind {
+    /// The shared credentials file, usually `~/.aws/credentials`, or as set by
+    /// `AWS_SHARED_CREDENTIALS_FILE`
+    Credentials,
+
+    /// The shared config file, usually `~/.aws/config`, or as set by `AWS_CONFIG_FILE`
+    Config,
+}
+
+#[derive(Debug, Clone)]
+enum Source {
+    /// Use the default profile file location for this kind of profile file
+    Default,
+
+    /// Read the profile file from this explicit path
+    File(PathBuf),
+}
+
+#[derive(Debug, Clone)]
+struct ProfileFile {
+    kind: ProfileFileKind,
+    source: Source,
+}
+
+/// The files composing the profile, e.g. the shared config and shared credentials file
+///
+/// By default, a [`ProfileFiles`] loads the default profile file locations (honoring the
+/// `AWS_CONFIG_FILE` and `AWS_SHARED_CREDENTIALS_FILE` environment variables). Use
+/// [`Builder`] to point a provider at explicit file paths, or to layer multiple files of the
+/// same kind together.
+///
+/// # Examples
+///
+/// Load the shared config file from a non-standard location:
+/// ```no_run
+/// use aws_config::profile::profile_file::{ProfileFiles, ProfileFileKind};
+/// let profile_files = ProfileFiles::builder()
+///     .with_file(ProfileFileKind::Config, "some/path/to/config.ini")
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProfileFiles {
+    files: Vec<ProfileFile>,
+}
+
+impl Default for ProfileFiles {
+    fn default() -> Self {
+        ProfileFiles {
+            files: vec![
+                ProfileFile {
+                    kind: ProfileFileKind::Credentials,
+                    source: Source::Default,
+                },
+                ProfileFile {
+                    kind: ProfileFileKind::Config,
+                    source: Source::Default,
+                },
+            ],
+        }
+    }
+}
+
+impl ProfileFiles {
+    /// Builder for [`ProfileFiles`]
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+}
+
+/// Builder for [`ProfileFiles`]
+#[derive(Debug, Default)]
+pub struct Builder {
+    files: Vec<ProfileFile>,
+}
+
+impl Builder {
+    /// Add a configuration file to the chain
+    ///
+    /// Multiple files of the same [`ProfileFileKind`] may be added: they will be merged
+    /// together in the order they were added, with later files taking precedence.
+    pub fn with_file(mut self, kind: ProfileFileKind, path: impl Into<PathBuf>) -> Self {
+        self.files.push(ProfileFile {
+            kind,
+            source: Source::File(path.into()),
+        });
+        self
+    }
+
+    /// Add the default configuration file location to the chain
+    pub fn with_default_file(mut self, kind: ProfileFileKind) -> Self {
+        self.files.push(ProfileFile {
+            kind,
+            source: Source::Default,
+        });
+        self
+    }
+
+    /// Build a [`ProfileFiles`] from this builder
+    pub fn build(self) -> ProfileFiles {
+        ProfileFiles { files: self.files }
+    }
+}
+
+impl ProfileFiles {
+    pub(crate) fn files(&self) -> impl Iterator<Item = (&ProfileFileKind, Option<&PathBuf>)> {
+        self.files.iter().map(|file| {
+            (
+                &file.kind,
+                match &file.source {
+                    Source::Default => None,
+                    Source::File(path) => Some(path),
+                },
+            )
+        })
+    }
+}
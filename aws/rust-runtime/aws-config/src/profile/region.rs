@@ -6,6 +6,7 @@
 //! Load a region from an AWS profile
 
 use crate::meta::region::{future, ProvideRegion};
+use crate::profile::profile_file::ProfileFiles;
 use crate::provider_config::ProviderConfig;
 use aws_types::os_shim_internal::{Env, Fs};
 use aws_types::region::Region;
@@ -38,7 +39,9 @@ use super::ProfileSet;
 pub struct ProfileFileRegionProvider {
     fs: Fs,
     env: Env,
+    profile_files: ProfileFiles,
     profile_override: Option<String>,
+    fallback_region: Option<Region>,
 }
 
 /// Builder for [ProfileFileRegionProvider]
@@ -46,6 +49,8 @@ pub struct ProfileFileRegionProvider {
 pub struct Builder {
     config: Option<ProviderConfig>,
     profile_override: Option<String>,
+    profile_files: Option<ProfileFiles>,
+    fallback_region: Option<Region>,
 }
 
 impl Builder {
@@ -61,13 +66,37 @@ impl Builder {
         self
     }
 
+    /// Set the [`ProfileFiles`] that should be used by this provider
+    ///
+    /// By default, this provider will load the standard locations for the shared config and
+    /// credentials files, honoring the `AWS_CONFIG_FILE` and `AWS_SHARED_CREDENTIALS_FILE`
+    /// environment variables. Use this method to point the provider at explicit file paths
+    /// instead, or to layer several files of the same kind together.
+    pub fn profile_files(mut self, profile_files: ProfileFiles) -> Self {
+        self.profile_files = Some(profile_files);
+        self
+    }
+
+    /// Set a region to fall back on when the profile chain does not specify a `region`
+    ///
+    /// This is only used when no profile in the `source_profile` chain defines a `region`. It
+    /// has no effect when a profile does specify one — the profile's `region` always wins over
+    /// this fallback. Note that this is distinct from an explicit region set elsewhere (e.g. on a
+    /// client builder), which should always take precedence over this provider's result entirely.
+    pub fn region_fallback(mut self, region: impl Into<Region>) -> Self {
+        self.fallback_region = Some(region.into());
+        self
+    }
+
     /// Build a [ProfileFileRegionProvider] from this builder
     pub fn build(self) -> ProfileFileRegionProvider {
         let conf = self.config.unwrap_or_default();
         ProfileFileRegionProvider {
             env: conf.env(),
             fs: conf.fs(),
+            profile_files: self.profile_files.unwrap_or_default(),
             profile_override: self.profile_override,
+            fallback_region: self.fallback_region,
         }
     }
 }
@@ -80,7 +109,9 @@ impl ProfileFileRegionProvider {
         Self {
             fs: Fs::real(),
             env: Env::real(),
+            profile_files: ProfileFiles::default(),
             profile_override: None,
+            fallback_region: None,
         }
     }
 
@@ -90,12 +121,13 @@ impl ProfileFileRegionProvider {
     }
 
     async fn region(&self) -> Option<Region> {
-        let profile_set = super::parser::load(&self.fs, &self.env)
+        let profile_set = super::parser::load(&self.fs, &self.env, &self.profile_files)
             .await
             .map_err(|err| tracing::warn!(err = %err, "failed to parse profile"))
             .ok()?;
 
         resolve_profile_chain_for_region(&profile_set, self.profile_override.as_deref())
+            .or_else(|| self.fallback_region.clone())
     }
 }
 
@@ -156,6 +188,7 @@ impl ProvideRegion for ProfileFileRegionProvider {
 
 #[cfg(test)]
 mod test {
+    use crate::profile::profile_file::{ProfileFileKind, ProfileFiles};
     use crate::profile::ProfileFileRegionProvider;
     use crate::provider_config::ProviderConfig;
     use crate::test_case::no_traffic_connector;
@@ -257,4 +290,183 @@ role_arn = arn:aws:iam::123456789012:role/test
                 .await
         );
     }
+
+    #[tokio::test]
+    async fn profile_files_explicit_path_ignores_env() {
+        let fs = Fs::from_slice(&[
+            ("explicit_config", "[default]\nregion = us-east-1\n"),
+            ("env_config", "[default]\nregion = us-west-1\n"),
+        ]);
+        // AWS_CONFIG_FILE points at a file with a different region: this only passes if the
+        // explicit path is used and the env var is not consulted.
+        let provider_config = ProviderConfig::empty()
+            .with_fs(fs)
+            .with_env(Env::from_slice(&[("AWS_CONFIG_FILE", "env_config")]))
+            .with_http_connector(no_traffic_connector());
+
+        let profile_files = ProfileFiles::builder()
+            .with_file(ProfileFileKind::Config, "explicit_config")
+            .build();
+
+        assert_eq!(
+            Some(Region::new("us-east-1")),
+            ProfileFileRegionProvider::builder()
+                .configure(&provider_config)
+                .profile_files(profile_files)
+                .build()
+                .region()
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn profile_files_later_file_wins_merge() {
+        let fs = Fs::from_slice(&[
+            ("first_config", "[default]\nregion = us-west-1\n"),
+            ("second_config", "[default]\nregion = us-east-1\n"),
+        ]);
+        let provider_config = ProviderConfig::empty()
+            .with_fs(fs)
+            .with_env(Env::from_slice(&[]))
+            .with_http_connector(no_traffic_connector());
+
+        let profile_files = ProfileFiles::builder()
+            .with_file(ProfileFileKind::Config, "first_config")
+            .with_file(ProfileFileKind::Config, "second_config")
+            .build();
+
+        assert_eq!(
+            Some(Region::new("us-east-1")),
+            ProfileFileRegionProvider::builder()
+                .configure(&provider_config)
+                .profile_files(profile_files)
+                .build()
+                .region()
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn profile_files_default_file_layered_after_explicit_wins() {
+        let fs = Fs::from_slice(&[
+            ("explicit_config", "[default]\nregion = us-west-1\n"),
+            ("/home/.aws/config", "[default]\nregion = us-east-1\n"),
+        ]);
+        let env = Env::from_slice(&[("HOME", "/home")]);
+        let provider_config = ProviderConfig::empty()
+            .with_fs(fs)
+            .with_env(env)
+            .with_http_connector(no_traffic_connector());
+
+        // `with_default_file` is added after the explicit file, so the default location's
+        // region should win the merge.
+        let profile_files = ProfileFiles::builder()
+            .with_file(ProfileFileKind::Config, "explicit_config")
+            .with_default_file(ProfileFileKind::Config)
+            .build();
+
+        assert_eq!(
+            Some(Region::new("us-east-1")),
+            ProfileFileRegionProvider::builder()
+                .configure(&provider_config)
+                .profile_files(profile_files)
+                .build()
+                .region()
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn region_fallback_used_when_profile_has_no_region() {
+        let config = r#"
+[profile needs-source]
+source_profile = credentials
+
+[profile credentials]
+aws_access_key_id = test-access-key-id
+"#
+        .trim();
+
+        let fs = Fs::from_slice(&[("test_config", config)]);
+        let env = Env::from_slice(&[("AWS_CONFIG_FILE", "test_config")]);
+        let provider_config = ProviderConfig::empty()
+            .with_fs(fs)
+            .with_env(env)
+            .with_http_connector(no_traffic_connector());
+
+        assert_eq!(
+            Some(Region::new("us-west-2")),
+            ProfileFileRegionProvider::builder()
+                .profile_name("needs-source")
+                .configure(&provider_config)
+                .region_fallback(Region::new("us-west-2"))
+                .build()
+                .region()
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn region_fallback_ignored_when_profile_has_region() {
+        let config = "[default]\nregion = us-east-1\n";
+
+        let fs = Fs::from_slice(&[("test_config", config)]);
+        let env = Env::from_slice(&[("AWS_CONFIG_FILE", "test_config")]);
+        let provider_config = ProviderConfig::empty()
+            .with_fs(fs)
+            .with_env(env)
+            .with_http_connector(no_traffic_connector());
+
+        assert_eq!(
+            Some(Region::new("us-east-1")),
+            ProfileFileRegionProvider::builder()
+                .configure(&provider_config)
+                .region_fallback(Region::new("us-west-2"))
+                .build()
+                .region()
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn parser_load_missing_explicit_file_is_an_error() {
+        let fs = Fs::from_slice(&[]);
+        let env = Env::from_slice(&[]);
+        let profile_files = ProfileFiles::builder()
+            .with_file(ProfileFileKind::Config, "does_not_exist")
+            .build();
+
+        assert!(super::super::parser::load(&fs, &env, &profile_files)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn parser_load_missing_default_file_is_not_an_error() {
+        let fs = Fs::from_slice(&[]);
+        // HOME resolves the default locations, but neither file exists in the filesystem.
+        let env = Env::from_slice(&[("HOME", "/home")]);
+        let profile_files = ProfileFiles::default();
+
+        let profile_set = super::super::parser::load(&fs, &env, &profile_files)
+            .await
+            .expect("a missing default file is treated as empty, not an error");
+        assert!(profile_set.is_empty());
+    }
+
+    #[tokio::test]
+    async fn parser_load_non_not_found_error_on_default_file_is_still_an_error() {
+        // `fs/home/.aws/config` is a directory, not a file, so reading it fails with an I/O
+        // error other than `NotFound`. That must still be surfaced, not treated as "absent".
+        let fs = Fs::from_test_dir(
+            "test-data/profile-provider/unreadable_default_config/fs",
+            "/",
+        );
+        let env = Env::from_slice(&[("HOME", "/home")]);
+        let profile_files = ProfileFiles::default();
+
+        super::super::parser::load(&fs, &env, &profile_files)
+            .await
+            .expect_err("a non-NotFound I/O error on a default-location file must propagate");
+    }
 }
@@ -72,6 +72,16 @@ impl OperationExtension {
         }
     }
 
+    /// Returns the Smithy model namespace.
+    pub fn namespace(&self) -> &'static str {
+        self.namespace
+    }
+
+    /// Returns the Smithy operation name.
+    pub fn operation_name(&self) -> &'static str {
+        self.operation_name
+    }
+
     /// Returns the current operation formatted as `<namespace>#<operation_name>`.
     pub fn operation(&self) -> String {
         format!("{}#{}", self.namespace, self.operation_name)
@@ -149,3 +159,47 @@ where
 
     Ok(Extension(value))
 }
+
+/// Extract an [`OperationExtension`] from a response's extensions.
+///
+/// This is the analogue of [`extract_extension`] for [`OperationExtension`]: rather than a
+/// request extension, [`OperationExtension`] is stored in the extensions of the response that the
+/// framework generates once it has determined which operation the request routes to. This lets
+/// post-processing `tower::Layer`s (for example metrics or logging layers) pull out the namespace
+/// and operation name without re-parsing the `#`-joined [`OperationExtension::operation`] string.
+pub fn extract_operation_extension(
+    extensions: &http::Extensions,
+) -> Result<&OperationExtension, crate::rejection::OperationExtensionNotFoundRejection> {
+    extensions
+        .get::<OperationExtension>()
+        .ok_or(crate::rejection::OperationExtensionNotFoundRejection::MissingExtension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operation_extension_exposes_namespace_and_operation_name() {
+        let extension = OperationExtension::new("com.example", "GetThing");
+        assert_eq!("com.example", extension.namespace());
+        assert_eq!("GetThing", extension.operation_name());
+        assert_eq!("com.example#GetThing", extension.operation());
+    }
+
+    #[test]
+    fn extract_operation_extension_finds_inserted_extension() {
+        let mut extensions = http::Extensions::new();
+        extensions.insert(OperationExtension::new("com.example", "GetThing"));
+
+        let extracted =
+            extract_operation_extension(&extensions).expect("extension was inserted above");
+        assert_eq!("GetThing", extracted.operation_name());
+    }
+
+    #[test]
+    fn extract_operation_extension_rejects_when_missing() {
+        let extensions = http::Extensions::new();
+        assert!(extract_operation_extension(&extensions).is_err());
+    }
+}
@@ -0,0 +1,29 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0.
+ */
+
+//! Rejection types for the extractors in [`crate::extension`].
+
+use std::fmt;
+
+/// The [`OperationExtension`](crate::extension::OperationExtension) was not found in the
+/// response's extensions.
+#[derive(Debug)]
+pub enum OperationExtensionNotFoundRejection {
+    /// The response's extensions did not contain an
+    /// [`OperationExtension`](crate::extension::OperationExtension).
+    MissingExtension,
+}
+
+impl fmt::Display for OperationExtensionNotFoundRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingExtension => {
+                write!(f, "OperationExtension was not found in response extensions")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OperationExtensionNotFoundRejection {}